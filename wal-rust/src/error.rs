@@ -0,0 +1,17 @@
+use thiserror::Error;
+
+/// Errors specific to reading back a record from the log, kept distinct from `anyhow`'s
+/// catch-all IO/serialization contexts so callers can tell corruption apart from a bit-rot
+/// that merely broke JSON syntax.
+#[derive(Debug, Error)]
+pub enum WalError {
+    #[error("log entry {sequence_number} at offset {offset} failed its checksum (expected {expected:#010x}, got {actual:#010x})")]
+    ChecksumMismatch {
+        offset: u64,
+        sequence_number: u64,
+        expected: u32,
+        actual: u32,
+    },
+    #[error("log entry at offset {offset} could not be parsed: {message}")]
+    ParseError { offset: u64, message: String },
+}
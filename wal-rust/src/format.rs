@@ -0,0 +1,109 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::constants::{LogEntry, OperationType};
+
+/// On-disk serialization backend for a `WriteAheadLog`'s data file. Recorded in an 8-byte
+/// header written when the data file is created, so reopening an existing log always picks
+/// the right codec regardless of what format the caller asks for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    /// One JSON object per record. Human-readable and the default; good for debugging.
+    Json,
+    /// Bincode-encoded records. Compact and fast, but opaque to a text editor.
+    Bincode,
+}
+
+/// Bincode can't deserialize `serde_json::Value` directly — its `Deserialize` impl relies on
+/// `deserialize_any`, which bincode's non-self-describing wire format doesn't support. So the
+/// bincode wire representation of a `LogEntry` carries `old_value`/`new_value` pre-rendered to
+/// JSON text instead, and `encode`/`decode` transcode to and from that on the way in and out.
+#[derive(Serialize, Deserialize)]
+struct BincodeEntry {
+    sequence_number: u64,
+    transaction_id: String,
+    operation_type: OperationType,
+    key: String,
+    old_value: Option<String>,
+    new_value: Option<String>,
+    timestamp: f64,
+    checksum: u32,
+}
+
+impl LogFormat {
+    pub(crate) const HEADER_SIZE: u64 = 8;
+    const MAGIC: [u8; 4] = *b"WAL1";
+
+    pub(crate) fn header_bytes(self) -> [u8; 8] {
+        let mut header = [0u8; 8];
+        header[..4].copy_from_slice(&Self::MAGIC);
+        header[4] = match self {
+            LogFormat::Json => 0,
+            LogFormat::Bincode => 1,
+        };
+        header
+    }
+
+    pub(crate) fn from_header_bytes(header: &[u8; 8]) -> Result<Self> {
+        if header[..4] != Self::MAGIC {
+            anyhow::bail!("Log file header is missing the expected WAL1 magic bytes");
+        }
+        match header[4] {
+            0 => Ok(LogFormat::Json),
+            1 => Ok(LogFormat::Bincode),
+            other => anyhow::bail!("Log file header references unknown format tag {}", other),
+        }
+    }
+
+    pub(crate) fn encode(self, entry: &LogEntry) -> Result<Vec<u8>> {
+        match self {
+            LogFormat::Json => serde_json::to_vec(entry).context("Failed to serialize log entry as JSON"),
+            LogFormat::Bincode => {
+                let shadow = BincodeEntry {
+                    sequence_number: entry.sequence_number,
+                    transaction_id: entry.transaction_id.clone(),
+                    operation_type: entry.operation_type.clone(),
+                    key: entry.key.clone(),
+                    old_value: Self::value_to_json_text(&entry.old_value)?,
+                    new_value: Self::value_to_json_text(&entry.new_value)?,
+                    timestamp: entry.timestamp,
+                    checksum: entry.checksum,
+                };
+                bincode::serialize(&shadow).context("Failed to serialize log entry as bincode")
+            }
+        }
+    }
+
+    pub(crate) fn decode(self, bytes: &[u8]) -> Result<LogEntry> {
+        match self {
+            LogFormat::Json => serde_json::from_slice(bytes).context("Failed to parse JSON log entry"),
+            LogFormat::Bincode => {
+                let shadow: BincodeEntry =
+                    bincode::deserialize(bytes).context("Failed to parse bincode log entry")?;
+                Ok(LogEntry {
+                    sequence_number: shadow.sequence_number,
+                    transaction_id: shadow.transaction_id,
+                    operation_type: shadow.operation_type,
+                    key: shadow.key,
+                    old_value: Self::json_text_to_value(shadow.old_value)?,
+                    new_value: Self::json_text_to_value(shadow.new_value)?,
+                    timestamp: shadow.timestamp,
+                    checksum: shadow.checksum,
+                })
+            }
+        }
+    }
+
+    fn value_to_json_text(value: &Option<Value>) -> Result<Option<String>> {
+        value
+            .as_ref()
+            .map(|v| serde_json::to_string(v).context("Failed to render value as JSON text"))
+            .transpose()
+    }
+
+    fn json_text_to_value(text: Option<String>) -> Result<Option<Value>> {
+        text.map(|s| serde_json::from_str(&s).context("Failed to parse value from JSON text"))
+            .transpose()
+    }
+}
@@ -0,0 +1,12 @@
+pub mod constants;
+pub mod error;
+pub mod format;
+pub mod recovery;
+pub mod verify;
+pub mod wal_basic;
+
+pub use error::WalError;
+pub use format::LogFormat;
+pub use recovery::RecoveryReport;
+pub use verify::VerificationReport;
+pub use wal_basic::WriteAheadLog;
@@ -0,0 +1,28 @@
+/// Outcome of the crash-recovery audit performed when an existing log is reopened.
+///
+/// The audit trusts the invariant that `write_log_entry` appends to the data file before
+/// it appends to the index file, so only the tail of each file can ever be inconsistent.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RecoveryReport {
+    /// Sequence numbers whose index entry pointed at a data record the data file could not
+    /// back (the data file was shorter than the record the index promised), and so were
+    /// dropped from the index.
+    pub dropped_index_entries: Vec<u64>,
+    /// Trailing bytes truncated from the data file because they sat beyond the last
+    /// surviving indexed record (a torn or never-indexed append).
+    pub truncated_data_bytes: u64,
+    /// Trailing bytes truncated from the index file because they didn't form a complete
+    /// `INDEX_ENTRY_SIZE`-byte offset (a crash mid-append to the index).
+    pub truncated_index_bytes: u64,
+    /// The sequence counter reconciled from the index, ready for the next write to continue from.
+    pub recovered_sequence_counter: u64,
+}
+
+impl RecoveryReport {
+    /// True if the audit found nothing to repair.
+    pub fn is_clean(&self) -> bool {
+        self.dropped_index_entries.is_empty()
+            && self.truncated_data_bytes == 0
+            && self.truncated_index_bytes == 0
+    }
+}
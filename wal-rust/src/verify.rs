@@ -0,0 +1,67 @@
+use std::ops::RangeInclusive;
+
+/// A contiguous run of sequence numbers sharing the same verification outcome.
+pub type SequenceRange = RangeInclusive<u64>;
+
+/// Per-record outcome produced while walking a log during `verify`/`verify_parallel`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordStatus {
+    Valid,
+    Unparseable,
+    ChecksumFailed,
+}
+
+/// Result of walking an entire log and classifying every record. Consecutive sequence
+/// numbers sharing an outcome are coalesced into a single range, since logs are usually
+/// either entirely healthy or corrupt over contiguous spans.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VerificationReport {
+    pub valid: Vec<SequenceRange>,
+    pub unparseable: Vec<SequenceRange>,
+    pub checksum_failed: Vec<SequenceRange>,
+}
+
+impl VerificationReport {
+    /// True if every record in the log was valid.
+    pub fn is_healthy(&self) -> bool {
+        self.unparseable.is_empty() && self.checksum_failed.is_empty()
+    }
+}
+
+/// Groups a sequence of `(sequence_number, status)` pairs, assumed to be in increasing
+/// order of `sequence_number`, into per-status coalesced ranges.
+pub(crate) fn coalesce_by_status(
+    classified: impl IntoIterator<Item = (u64, RecordStatus)>,
+) -> VerificationReport {
+    let mut report = VerificationReport::default();
+    let mut open: Option<(RecordStatus, u64, u64)> = None;
+
+    for (sequence_number, status) in classified {
+        match open {
+            Some((open_status, start, end)) if open_status == status && sequence_number == end + 1 => {
+                open = Some((open_status, start, sequence_number));
+            }
+            Some((open_status, start, end)) => {
+                push_range(&mut report, open_status, start..=end);
+                open = Some((status, sequence_number, sequence_number));
+            }
+            None => {
+                open = Some((status, sequence_number, sequence_number));
+            }
+        }
+    }
+
+    if let Some((status, start, end)) = open {
+        push_range(&mut report, status, start..=end);
+    }
+
+    report
+}
+
+fn push_range(report: &mut VerificationReport, status: RecordStatus, range: SequenceRange) {
+    match status {
+        RecordStatus::Valid => report.valid.push(range),
+        RecordStatus::Unparseable => report.unparseable.push(range),
+        RecordStatus::ChecksumFailed => report.checksum_failed.push(range),
+    }
+}